@@ -18,6 +18,9 @@ pub mod secp256kfun;
 
 use crate::DLEqResult;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 #[allow(non_snake_case)]
 pub struct KeyBundle {
   pub dl_eq: Vec<u8>,
@@ -32,32 +35,56 @@ pub struct Commitment<Engine: DLEqEngine> {
   pub commitment_minus_one: Engine::PublicKey,
 }
 
+// blinding_key is the only secret here; commitment/commitment_minus_one are public points
+#[cfg(feature = "zeroize")]
+impl<Engine: DLEqEngine> Drop for Commitment<Engine> {
+  fn drop(&mut self) {
+    self.blinding_key.zeroize();
+  }
+}
+
 pub trait BasepointProvider {
   type Point;
   fn basepoint() -> Self::Point;
   fn alt_basepoint() -> Self::Point;
 }
 
+// Lets PrivateKey require Zeroize when the zeroize feature is on, without forcing it on
+// no-alloc/embedded users who opt out by disabling the default feature.
+#[cfg(feature = "zeroize")]
+pub trait MaybeZeroize: Zeroize {}
+#[cfg(feature = "zeroize")]
+impl<T: Zeroize> MaybeZeroize for T {}
+
+#[cfg(not(feature = "zeroize"))]
+pub trait MaybeZeroize {}
+#[cfg(not(feature = "zeroize"))]
+impl<T> MaybeZeroize for T {}
+
 pub trait DLEqEngine: Sized {
-  type PrivateKey: PartialEq + Clone + Sized + Send + Sync + 'static;
+  type PrivateKey: PartialEq + Clone + Sized + Send + Sync + 'static + MaybeZeroize;
   type PublicKey: PartialEq + Clone + Sized + Send + Sync + 'static;
   type Signature: PartialEq + Clone + Sized + Send + Sync + 'static;
+  type PreSignature: PartialEq + Clone + Sized + Send + Sync + 'static;
 
   fn scalar_bits() -> usize;
 
   fn new_private_key<R: RngCore + CryptoRng>(rng: &mut R) -> Self::PrivateKey;
   fn to_public_key(key: &Self::PrivateKey) -> Self::PublicKey;
 
-  fn little_endian_bytes_to_private_key(bytes: [u8; 32]) -> DLEqResult<Self::PrivateKey>;
+  // Rejects non-canonical encodings; use for deserializing externally supplied keys
+  fn from_canonical_bytes(bytes: [u8; 32]) -> DLEqResult<Self::PrivateKey>;
+  // Wide (64-byte) reduction, unbiased; use for challenges and anything else derived from a hash
+  fn from_uniform_bytes(bytes: [u8; 64]) -> Self::PrivateKey;
   fn private_key_to_little_endian_bytes(key: &Self::PrivateKey) -> [u8; 32];
   fn public_key_to_bytes(key: &Self::PublicKey) -> Vec<u8>;
   fn bytes_to_public_key(key: &[u8]) -> DLEqResult<Self::PublicKey>;
 
   fn generate_commitments<R: RngCore + CryptoRng>(rng: &mut R, key: [u8; 32], bits: usize) -> Vec<Commitment<Self>>;
-  fn compute_signature_s(nonce: &Self::PrivateKey, challenge: [u8; 32], key: &Self::PrivateKey) -> Self::PrivateKey;
+  fn compute_signature_s(nonce: &Self::PrivateKey, challenge: [u8; 64], key: &Self::PrivateKey) -> Self::PrivateKey;
   // Forced to be Results by the secp256kfun backend which forces a NonZero check which can fail based on counterparty supplied data
   #[allow(non_snake_case)]
-  fn compute_signature_R(s_value: &Self::PrivateKey, challenge: [u8; 32], key: &Self::PublicKey) -> DLEqResult<Self::PublicKey>;
+  fn compute_signature_R(s_value: &Self::PrivateKey, challenge: [u8; 64], key: &Self::PublicKey) -> DLEqResult<Self::PublicKey>;
   fn commitment_sub_one(commitment: &Self::PublicKey) -> DLEqResult<Self::PublicKey>;
   // This returning a Result also provides an opportunity to check for torsion,
   // yet the deserializers should prevent that in the first place
@@ -66,9 +93,37 @@ pub trait DLEqEngine: Sized {
 
   fn sign(secret_key: &Self::PrivateKey, message: &[u8]) -> Self::Signature;
   fn verify_signature(public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> DLEqResult<()>;
+  // Verifies every entry via a single random linear combination instead of one multiscalar
+  // multiplication per signature. entries are (public_key, message, signature) triples.
+  fn verify_batch<R: RngCore + CryptoRng>(entries: &[(Self::PublicKey, &[u8], Self::Signature)], rng: &mut R) -> DLEqResult<()>;
+
+  // Schnorr adaptor signatures. The challenge is computed over the completed nonce R + Y in both
+  // adaptor_sign and adapt, so the resulting signature verifies under verify_signature unmodified
+  // and is indistinguishable from one produced by sign directly.
+  // TODO(chunk0-1): only implemented for RistrettoEngine so far. The request also covers
+  // Ed25519Engine and the secp256k1/k256/p256 backends; the Ed25519Engine in dl_eq_engines/ still
+  // implements the older, separate DlEqEngine trait rather than this one, and no k256/p256/
+  // secp256kfun DLEqEngine implementation exists in this checkout to extend. Treat this request as
+  // open, not done, until those engines are ported to DLEqEngine and given their own
+  // adaptor_sign/adaptor_verify/adapt/extract impls.
+  fn adaptor_sign(secret_key: &Self::PrivateKey, message: &[u8], encryption_point: &Self::PublicKey) -> Self::PreSignature;
+  fn adaptor_verify(public_key: &Self::PublicKey, message: &[u8], encryption_point: &Self::PublicKey, pre_signature: &Self::PreSignature) -> DLEqResult<()>;
+  fn adapt(pre_signature: &Self::PreSignature, secret: &Self::PrivateKey) -> Self::Signature;
+  fn extract(signature: &Self::Signature, pre_signature: &Self::PreSignature) -> DLEqResult<Self::PrivateKey>;
 
   fn point_len() -> usize;
   fn signature_len() -> usize;
   fn signature_to_bytes(signature: &Self::Signature) -> Vec<u8>;
   fn bytes_to_signature(signature: &[u8]) -> DLEqResult<Self::Signature>;
+
+  // Minimal scalar/point field operations, exposed so engine-agnostic subsystems built atop this
+  // trait (e.g. vss::split_key/reconstruct) can do polynomial evaluation and Lagrange
+  // interpolation without each engine reimplementing them.
+  fn private_key_from_u16(value: u16) -> Self::PrivateKey;
+  fn add_private_keys(a: &Self::PrivateKey, b: &Self::PrivateKey) -> Self::PrivateKey;
+  fn negate_private_key(key: &Self::PrivateKey) -> Self::PrivateKey;
+  fn mul_private_keys(a: &Self::PrivateKey, b: &Self::PrivateKey) -> Self::PrivateKey;
+  fn invert_private_key(key: &Self::PrivateKey) -> DLEqResult<Self::PrivateKey>;
+  fn add_public_keys(a: &Self::PublicKey, b: &Self::PublicKey) -> Self::PublicKey;
+  fn scale_public_key(point: &Self::PublicKey, scalar: &Self::PrivateKey) -> Self::PublicKey;
 }
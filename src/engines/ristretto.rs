@@ -8,13 +8,16 @@ use blake2::{Digest, Blake2b};
 
 use curve25519_dalek::{
   constants::{RISTRETTO_BASEPOINT_TABLE, RISTRETTO_BASEPOINT_POINT},
-  traits::Identity,
+  traits::{Identity, VartimeMultiscalarMul},
   scalar::Scalar,
   ristretto::{RistrettoPoint, CompressedRistretto}
 };
 
 use log::debug;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 use crate::{DLEqError, DLEqResult, engines::{DLEqEngine, Commitment}};
 
 lazy_static! {
@@ -30,11 +33,19 @@ pub struct Signature {
   s: Scalar
 }
 
+#[derive(Clone, PartialEq)]
+#[allow(non_snake_case)]
+pub struct PreSignature {
+  R: RistrettoPoint,
+  s_hat: Scalar
+}
+
 pub struct RistrettoEngine;
 impl DLEqEngine for RistrettoEngine {
   type PrivateKey = Scalar;
   type PublicKey = RistrettoPoint;
   type Signature = Signature;
+  type PreSignature = PreSignature;
 
   fn alt_basepoint() -> Self::PublicKey {
     *ALT_BASEPOINT
@@ -52,10 +63,14 @@ impl DLEqEngine for RistrettoEngine {
     key * &RISTRETTO_BASEPOINT_TABLE
   }
 
-  fn little_endian_bytes_to_private_key(bytes: [u8; 32]) -> DLEqResult<Self::PrivateKey> {
+  fn from_canonical_bytes(bytes: [u8; 32]) -> DLEqResult<Self::PrivateKey> {
     Scalar::from_canonical_bytes(bytes).ok_or(DLEqError::InvalidScalar)
   }
 
+  fn from_uniform_bytes(bytes: [u8; 64]) -> Self::PrivateKey {
+    Scalar::from_bytes_mod_order_wide(&bytes)
+  }
+
   fn private_key_to_little_endian_bytes(key: &Self::PrivateKey) -> [u8; 32] {
     key.to_bytes()
   }
@@ -97,7 +112,7 @@ impl DLEqEngine for RistrettoEngine {
     }
 
     debug_assert_eq!(blinding_key_total, Scalar::zero());
-    let pubkey = &Scalar::from_canonical_bytes(key).expect(
+    let pubkey = &Self::from_canonical_bytes(key).expect(
       "Generating commitments for an invalid Ristretto key"
     ) * &RISTRETTO_BASEPOINT_TABLE;
     debug_assert_eq!(
@@ -106,15 +121,26 @@ impl DLEqEngine for RistrettoEngine {
     );
     debug!("Generated DL Eq proof for Ristretto pubkey {}", hex::encode(pubkey.compress().as_bytes()));
 
+    #[cfg(feature = "zeroize")]
+    {
+      blinding_key_total.zeroize();
+      power_of_two.zeroize();
+    }
+
     commitments
   }
 
-  fn compute_signature_s(nonce: &Self::PrivateKey, challenge: [u8; 32], key: &Self::PrivateKey) -> Self::PrivateKey {
-    nonce + Scalar::from_bytes_mod_order(challenge) * key
+  fn compute_signature_s(nonce: &Self::PrivateKey, challenge: [u8; 64], key: &Self::PrivateKey) -> Self::PrivateKey {
+    #[allow(unused_mut)]
+    let mut c_key = Self::from_uniform_bytes(challenge) * key;
+    let s = nonce + c_key;
+    #[cfg(feature = "zeroize")]
+    c_key.zeroize();
+    s
   }
 
-  fn compute_signature_R(s_value: &Self::PrivateKey, challenge: [u8; 32], key: &Self::PublicKey) -> DLEqResult<Self::PublicKey> {
-    Ok(s_value * *ALT_BASEPOINT - Scalar::from_bytes_mod_order(challenge) * key)
+  fn compute_signature_R(s_value: &Self::PrivateKey, challenge: [u8; 64], key: &Self::PublicKey) -> DLEqResult<Self::PublicKey> {
+    Ok(s_value * *ALT_BASEPOINT - Self::from_uniform_bytes(challenge) * key)
   }
 
   fn commitment_sub_one(commitment: &Self::PublicKey) -> DLEqResult<Self::PublicKey> {
@@ -137,13 +163,17 @@ impl DLEqEngine for RistrettoEngine {
   }
 
   fn sign(key: &Self::PrivateKey, message: &[u8]) -> Self::Signature {
-      let k = Scalar::from_hash(Blake2b::new().chain(key.to_bytes()).chain(message));
+      #[allow(unused_mut)]
+      let mut k = Scalar::from_hash(Blake2b::new().chain(key.to_bytes()).chain(message));
       #[allow(non_snake_case)]
       let R = &RISTRETTO_BASEPOINT_POINT * k;
 
       let mut to_hash = R.compress().as_bytes().to_vec();
       to_hash.extend(message);
-      let s = k - (*key * Scalar::from_bytes_mod_order(Blake2b::digest(&to_hash)[..32].try_into().unwrap()));
+      let s = k - (*key * Self::from_uniform_bytes(Blake2b::digest(&to_hash).into()));
+
+      #[cfg(feature = "zeroize")]
+      k.zeroize();
 
       Signature { R, s }
   }
@@ -151,7 +181,7 @@ impl DLEqEngine for RistrettoEngine {
   fn verify_signature(public_key: &Self::PublicKey, message: &[u8], signature: &Self::Signature) -> DLEqResult<()> {
     let mut to_hash = signature.R.compress().as_bytes().to_vec();
     to_hash.extend(message);
-    let c = Scalar::from_bytes_mod_order(Blake2b::digest(&to_hash)[..32].try_into().unwrap());
+    let c = Self::from_uniform_bytes(Blake2b::digest(&to_hash).into());
     if RistrettoPoint::vartime_double_scalar_mul_basepoint(&c, &public_key, &signature.s) == signature.R {
       Ok(())
     } else {
@@ -159,6 +189,83 @@ impl DLEqEngine for RistrettoEngine {
     }
   }
 
+  fn verify_batch<R: RngCore + CryptoRng>(entries: &[(Self::PublicKey, &[u8], Self::Signature)], rng: &mut R) -> DLEqResult<()> {
+    let mut scalars = Vec::with_capacity(1 + (entries.len() * 2));
+    let mut points = Vec::with_capacity(1 + (entries.len() * 2));
+    let mut s_sum = Scalar::zero();
+
+    for (i, (public_key, message, signature)) in entries.iter().enumerate() {
+      let z = if i == 0 {
+        Scalar::one()
+      } else {
+        let mut bytes = [0; 32];
+        rng.fill_bytes(&mut bytes[..16]);
+        Scalar::from_bytes_mod_order(bytes)
+      };
+
+      let mut to_hash = signature.R.compress().as_bytes().to_vec();
+      to_hash.extend(*message);
+      let c = Self::from_uniform_bytes(Blake2b::digest(&to_hash).into());
+
+      s_sum += z * signature.s;
+      scalars.push(z);
+      scalars.push(-(z * c));
+      points.push(signature.R);
+      points.push(public_key.clone());
+    }
+
+    scalars.push(-s_sum);
+    points.push(RISTRETTO_BASEPOINT_POINT);
+
+    if RistrettoPoint::vartime_multiscalar_mul(&scalars, &points) == RistrettoPoint::identity() {
+      Ok(())
+    } else {
+      Err(DLEqError::InvalidSignature)
+    }
+  }
+
+  #[allow(non_snake_case)]
+  fn adaptor_sign(key: &Self::PrivateKey, message: &[u8], encryption_point: &Self::PublicKey) -> Self::PreSignature {
+    let r = Scalar::from_hash(
+      Blake2b::new().chain(key.to_bytes()).chain(encryption_point.compress().as_bytes()).chain(message)
+    );
+    let R = &RISTRETTO_BASEPOINT_POINT * r;
+
+    let mut to_hash = (R + encryption_point).compress().as_bytes().to_vec();
+    to_hash.extend(message);
+    let c = Self::from_uniform_bytes(Blake2b::digest(&to_hash).into());
+    let s_hat = r - (c * key);
+
+    PreSignature { R, s_hat }
+  }
+
+  fn adaptor_verify(public_key: &Self::PublicKey, message: &[u8], encryption_point: &Self::PublicKey, pre_signature: &Self::PreSignature) -> DLEqResult<()> {
+    let mut to_hash = (pre_signature.R + encryption_point).compress().as_bytes().to_vec();
+    to_hash.extend(message);
+    let c = Self::from_uniform_bytes(Blake2b::digest(&to_hash).into());
+    if RistrettoPoint::vartime_double_scalar_mul_basepoint(&c, public_key, &pre_signature.s_hat) == pre_signature.R {
+      Ok(())
+    } else {
+      Err(DLEqError::InvalidSignature)
+    }
+  }
+
+  #[allow(non_snake_case)]
+  fn adapt(pre_signature: &Self::PreSignature, secret: &Self::PrivateKey) -> Self::Signature {
+    Signature {
+      R: pre_signature.R + (secret * &RISTRETTO_BASEPOINT_TABLE),
+      s: pre_signature.s_hat + secret
+    }
+  }
+
+  fn extract(signature: &Self::Signature, pre_signature: &Self::PreSignature) -> DLEqResult<Self::PrivateKey> {
+    if signature.R != pre_signature.R + (signature.s - pre_signature.s_hat) * RISTRETTO_BASEPOINT_POINT {
+      Err(DLEqError::InvalidScalar)
+    } else {
+      Ok(signature.s - pre_signature.s_hat)
+    }
+  }
+
   fn point_len() -> usize {
     32
   }
@@ -180,11 +287,43 @@ impl DLEqEngine for RistrettoEngine {
       Ok(
         Self::Signature {
           R: Self::bytes_to_public_key(&sig[..32]).map_err(|_| DLEqError::InvalidSignature)?,
-          s: Self::little_endian_bytes_to_private_key(sig[32..].try_into().expect(
+          s: Self::from_canonical_bytes(sig[32..].try_into().expect(
             "Signature was correct length yet didn't have a 32-byte scalar")
           ).map_err(|_| DLEqError::InvalidSignature)?
         }
       )
     }
   }
+
+  fn private_key_from_u16(value: u16) -> Self::PrivateKey {
+    Scalar::from(value)
+  }
+
+  fn add_private_keys(a: &Self::PrivateKey, b: &Self::PrivateKey) -> Self::PrivateKey {
+    a + b
+  }
+
+  fn negate_private_key(key: &Self::PrivateKey) -> Self::PrivateKey {
+    -key
+  }
+
+  fn mul_private_keys(a: &Self::PrivateKey, b: &Self::PrivateKey) -> Self::PrivateKey {
+    a * b
+  }
+
+  fn invert_private_key(key: &Self::PrivateKey) -> DLEqResult<Self::PrivateKey> {
+    if key == &Scalar::zero() {
+      Err(DLEqError::InvalidScalar)
+    } else {
+      Ok(key.invert())
+    }
+  }
+
+  fn add_public_keys(a: &Self::PublicKey, b: &Self::PublicKey) -> Self::PublicKey {
+    a + b
+  }
+
+  fn scale_public_key(point: &Self::PublicKey, scalar: &Self::PrivateKey) -> Self::PublicKey {
+    point * scalar
+  }
 }
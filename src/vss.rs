@@ -0,0 +1,142 @@
+// Feldman verifiable secret sharing of the scalar a DLEqEngine proves equal across groups.
+// Splits the secret as the constant term of a degree-(threshold - 1) polynomial and publishes
+// commitments to its coefficients so each share can be checked against them before use.
+//
+// Swap setups that prove the same secret on two curves need matching per-engine commitments and
+// shares, which requires both engines' polynomials to share the same raw coefficients. split_key
+// draws those coefficients itself and hands them back as bytes; call split_coefficients with
+// that same byte vector against the second engine to get its matching commitments and shares
+// (each engine still rejects non-canonical coefficients via from_canonical_bytes). The existing
+// bit-commitment DLEq proof can then be generated over the reconstructed a_0 on either engine.
+
+use rand_core::{RngCore, CryptoRng};
+
+use crate::{DLEqError, DLEqResult, engines::DLEqEngine};
+
+#[derive(Clone)]
+pub struct Share<Engine: DLEqEngine> {
+  pub index: u16,
+  pub value: Engine::PrivateKey,
+}
+
+// Written by hand as derive(PartialEq) would require Engine itself to be PartialEq
+impl<Engine: DLEqEngine> PartialEq for Share<Engine> {
+  fn eq(&self, other: &Self) -> bool {
+    (self.index == other.index) && (self.value == other.value)
+  }
+}
+
+fn evaluate<Engine: DLEqEngine>(coefficients: &[Engine::PrivateKey], x: u16) -> Engine::PrivateKey {
+  let x = Engine::private_key_from_u16(x);
+  let mut result = coefficients[coefficients.len() - 1].clone();
+  for coefficient in coefficients[.. coefficients.len() - 1].iter().rev() {
+    result = Engine::add_private_keys(coefficient, &Engine::mul_private_keys(&result, &x));
+  }
+  result
+}
+
+// Splits coefficients (a_0 = the shared secret, a_1.. random) into one share per participant
+// 1 ..= shares plus commitments A_j = a_j*G to every coefficient. Calling this with the same
+// coefficient bytes against a second Engine yields that engine's matching commitments and shares,
+// which is how a cross-engine DLEQ-proven key gets VSS'd consistently on both curves.
+pub fn split_coefficients<Engine: DLEqEngine>(
+  coefficients: &[[u8; 32]],
+  shares: u16
+) -> DLEqResult<(Vec<Share<Engine>>, Vec<Engine::PublicKey>)> {
+  if coefficients.is_empty() || (shares < (coefficients.len() as u16)) {
+    return Err(DLEqError::InvalidScalar);
+  }
+
+  let coefficients = coefficients.iter().map(|bytes| Engine::from_canonical_bytes(*bytes)).collect::<DLEqResult<Vec<_>>>()?;
+
+  let commitments = coefficients.iter().map(Engine::to_public_key).collect();
+  let participant_shares = (1 ..= shares).map(
+    |i| Share { index: i, value: evaluate::<Engine>(&coefficients, i) }
+  ).collect();
+
+  Ok((participant_shares, commitments))
+}
+
+// Splits key into a degree-(threshold - 1) polynomial with a_0 = key, drawing the rest of the
+// coefficients from rng. Returns those coefficients (as bytes) alongside the usual shares and
+// commitments so they can be replayed through split_coefficients for a second engine.
+pub fn split_key<Engine: DLEqEngine, R: RngCore + CryptoRng>(
+  rng: &mut R,
+  key: [u8; 32],
+  threshold: u16,
+  shares: u16
+) -> DLEqResult<(Vec<Share<Engine>>, Vec<Engine::PublicKey>, Vec<[u8; 32]>)> {
+  if (threshold == 0) || (shares < threshold) {
+    return Err(DLEqError::InvalidScalar);
+  }
+
+  let mut coefficients = Vec::with_capacity(threshold as usize);
+  coefficients.push(key);
+  for _ in 1 .. threshold {
+    // new_private_key (not a raw fill_bytes) so the result is already a canonical in-field
+    // scalar; from_canonical_bytes below would otherwise reject ~15/16 of uniform byte draws.
+    let coefficient = Engine::new_private_key(rng);
+    coefficients.push(Engine::private_key_to_little_endian_bytes(&coefficient));
+  }
+
+  let (participant_shares, commitments) = split_coefficients::<Engine>(&coefficients, shares)?;
+
+  Ok((participant_shares, commitments, coefficients))
+}
+
+// Checks f(i)*G == sum_j i^j * A_j, i.e. that share was honestly derived from the polynomial
+// committed to by commitments.
+pub fn verify_share<Engine: DLEqEngine>(share: &Share<Engine>, commitments: &[Engine::PublicKey]) -> DLEqResult<()> {
+  if commitments.is_empty() {
+    return Err(DLEqError::InvalidScalar);
+  }
+
+  let x = Engine::private_key_from_u16(share.index);
+  let mut power = Engine::private_key_from_u16(1);
+  let mut expected = Engine::scale_public_key(&commitments[0], &power);
+  for commitment in &commitments[1 ..] {
+    power = Engine::mul_private_keys(&power, &x);
+    expected = Engine::add_public_keys(&expected, &Engine::scale_public_key(commitment, &power));
+  }
+
+  if Engine::to_public_key(&share.value) == expected {
+    Ok(())
+  } else {
+    Err(DLEqError::InvalidScalar)
+  }
+}
+
+// Lagrange-interpolates f(0) from shares. Errors if fewer than threshold shares are given, or if
+// two shares carry the same index (a zero denominator), rather than panicking on malformed
+// counterparty input. Callers are still responsible for having verify_share'd each share, as a
+// wrong-but-sufficiently-sized share set interpolates to a wrong secret rather than erroring.
+pub fn reconstruct<Engine: DLEqEngine>(shares: &[Share<Engine>], threshold: u16) -> DLEqResult<Engine::PrivateKey> {
+  if (threshold == 0) || (shares.len() < (threshold as usize)) {
+    return Err(DLEqError::InvalidScalar);
+  }
+
+  let mut secret: Option<Engine::PrivateKey> = None;
+  for (j, share_j) in shares.iter().enumerate() {
+    let x_j = Engine::private_key_from_u16(share_j.index);
+
+    let mut lambda = Engine::private_key_from_u16(1);
+    for (k, share_k) in shares.iter().enumerate() {
+      if j == k {
+        continue;
+      }
+
+      let x_k = Engine::private_key_from_u16(share_k.index);
+      let denominator = Engine::add_private_keys(&x_k, &Engine::negate_private_key(&x_j));
+      let inverted = Engine::invert_private_key(&denominator)?;
+      lambda = Engine::mul_private_keys(&Engine::mul_private_keys(&lambda, &x_k), &inverted);
+    }
+
+    let term = Engine::mul_private_keys(&lambda, &share_j.value);
+    secret = Some(match secret {
+      Some(acc) => Engine::add_private_keys(&acc, &term),
+      None => term
+    });
+  }
+
+  Ok(secret.expect("shares was checked non-empty above"))
+}
@@ -0,0 +1,38 @@
+// Happy-path and adversarial coverage for RistrettoEngine's Schnorr adaptor signatures.
+
+use rand::rngs::OsRng;
+
+use dleq::engines::{DLEqEngine, ristretto::RistrettoEngine};
+
+fn keypair() -> (<RistrettoEngine as DLEqEngine>::PrivateKey, <RistrettoEngine as DLEqEngine>::PublicKey) {
+  let key = RistrettoEngine::new_private_key(&mut OsRng);
+  let public = RistrettoEngine::to_public_key(&key);
+  (key, public)
+}
+
+#[test]
+fn adaptor_signature_roundtrip() {
+  let (x, public) = keypair();
+  let (y, encryption_point) = keypair();
+  let message = b"atomic swap completion";
+
+  let pre_signature = RistrettoEngine::adaptor_sign(&x, message, &encryption_point);
+  RistrettoEngine::adaptor_verify(&public, message, &encryption_point, &pre_signature).unwrap();
+
+  let signature = RistrettoEngine::adapt(&pre_signature, &y);
+  RistrettoEngine::verify_signature(&public, message, &signature).unwrap();
+
+  let extracted = RistrettoEngine::extract(&signature, &pre_signature).unwrap();
+  assert!(extracted == y);
+}
+
+#[test]
+fn adaptor_verify_rejects_mismatched_encryption_point() {
+  let (x, public) = keypair();
+  let (_, encryption_point) = keypair();
+  let (_, other_encryption_point) = keypair();
+  let message = b"atomic swap completion";
+
+  let pre_signature = RistrettoEngine::adaptor_sign(&x, message, &encryption_point);
+  assert!(RistrettoEngine::adaptor_verify(&public, message, &other_encryption_point, &pre_signature).is_err());
+}
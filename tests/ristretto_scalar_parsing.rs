@@ -0,0 +1,17 @@
+// Happy-path and adversarial coverage for canonical vs. uniform scalar parsing.
+
+use dleq::engines::{DLEqEngine, ristretto::RistrettoEngine};
+
+#[test]
+fn canonical_bytes_rejects_non_canonical_encodings() {
+  assert!(RistrettoEngine::from_canonical_bytes([0; 32]).is_ok());
+  // 0xff..ff is far larger than Ristretto's ~2^252 group order, so it must be rejected.
+  assert!(RistrettoEngine::from_canonical_bytes([0xff; 32]).is_err());
+}
+
+#[test]
+fn uniform_bytes_never_errors() {
+  // Wide reduction absorbs the bias of an arbitrary 64-byte hash, so it has no failure mode.
+  let _ = RistrettoEngine::from_uniform_bytes([0xff; 64]);
+  let _ = RistrettoEngine::from_uniform_bytes([0; 64]);
+}
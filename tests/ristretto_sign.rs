@@ -0,0 +1,41 @@
+// Happy-path and adversarial coverage for RistrettoEngine::sign/verify_signature/verify_batch.
+
+use rand::rngs::OsRng;
+
+use dleq::engines::{DLEqEngine, ristretto::RistrettoEngine};
+
+fn keypair() -> (<RistrettoEngine as DLEqEngine>::PrivateKey, <RistrettoEngine as DLEqEngine>::PublicKey) {
+  let key = RistrettoEngine::new_private_key(&mut OsRng);
+  let public = RistrettoEngine::to_public_key(&key);
+  (key, public)
+}
+
+#[test]
+fn sign_verify_roundtrip() {
+  let (key, public) = keypair();
+  let message = b"c-dleq sign/verify roundtrip";
+
+  let signature = RistrettoEngine::sign(&key, message);
+  RistrettoEngine::verify_signature(&public, message, &signature).unwrap();
+
+  assert!(RistrettoEngine::verify_signature(&public, b"a different message", &signature).is_err());
+}
+
+#[test]
+fn verify_batch_roundtrip() {
+  let mut entries = Vec::new();
+  for i in 0 .. 3 {
+    let (key, public) = keypair();
+    let message = format!("batch entry {}", i).into_bytes();
+    let signature = RistrettoEngine::sign(&key, &message);
+    entries.push((public, message, signature));
+  }
+
+  let borrowed: Vec<_> = entries.iter().map(|(public, message, signature)| (public.clone(), message.as_slice(), signature.clone())).collect();
+  RistrettoEngine::verify_batch(&borrowed, &mut OsRng).unwrap();
+
+  // Tamper with one entry's message; the whole batch must be rejected.
+  let mut tampered = borrowed;
+  tampered[1].1 = b"not the signed message";
+  assert!(RistrettoEngine::verify_batch(&tampered, &mut OsRng).is_err());
+}
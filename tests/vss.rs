@@ -0,0 +1,49 @@
+// Happy-path and adversarial coverage for the Feldman VSS subsystem.
+
+use rand::rngs::OsRng;
+
+use dleq::engines::{DLEqEngine, ristretto::RistrettoEngine};
+use dleq::vss;
+
+#[test]
+fn vss_roundtrip() {
+  let key = RistrettoEngine::new_private_key(&mut OsRng);
+  let key_bytes = RistrettoEngine::private_key_to_little_endian_bytes(&key);
+
+  let (shares, commitments, _coefficients) = vss::split_key::<RistrettoEngine, _>(&mut OsRng, key_bytes, 2, 3).unwrap();
+  for share in &shares {
+    vss::verify_share::<RistrettoEngine>(share, &commitments).unwrap();
+  }
+
+  let reconstructed = vss::reconstruct::<RistrettoEngine>(&shares[.. 2], 2).unwrap();
+  assert!(RistrettoEngine::to_public_key(&reconstructed) == RistrettoEngine::to_public_key(&key));
+}
+
+#[test]
+fn vss_reconstruct_rejects_too_few_shares() {
+  let key_bytes = RistrettoEngine::private_key_to_little_endian_bytes(&RistrettoEngine::new_private_key(&mut OsRng));
+  let (shares, _commitments, _coefficients) = vss::split_key::<RistrettoEngine, _>(&mut OsRng, key_bytes, 3, 3).unwrap();
+
+  assert!(vss::reconstruct::<RistrettoEngine>(&shares[.. 2], 3).is_err());
+}
+
+#[test]
+fn vss_verify_share_rejects_tampered_share() {
+  let key_bytes = RistrettoEngine::private_key_to_little_endian_bytes(&RistrettoEngine::new_private_key(&mut OsRng));
+  let (mut shares, commitments, _coefficients) = vss::split_key::<RistrettoEngine, _>(&mut OsRng, key_bytes, 2, 3).unwrap();
+
+  shares[0].value = RistrettoEngine::new_private_key(&mut OsRng);
+  assert!(vss::verify_share::<RistrettoEngine>(&shares[0], &commitments).is_err());
+}
+
+#[test]
+fn vss_split_coefficients_matches_split_key() {
+  let key = RistrettoEngine::new_private_key(&mut OsRng);
+  let key_bytes = RistrettoEngine::private_key_to_little_endian_bytes(&key);
+
+  let (shares, commitments, coefficients) = vss::split_key::<RistrettoEngine, _>(&mut OsRng, key_bytes, 2, 3).unwrap();
+  let (replayed_shares, replayed_commitments) = vss::split_coefficients::<RistrettoEngine>(&coefficients, 3).unwrap();
+
+  assert!(shares == replayed_shares);
+  assert!(commitments == replayed_commitments);
+}